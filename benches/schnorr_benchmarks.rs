@@ -13,7 +13,7 @@ use criterion::{BenchmarkId, Criterion, criterion_main, criterion_group};
 
 mod schnorr_benches {
     use super::*;
-    use schnorrkel::{signing_context, verify_batch, Keypair, PublicKey, Signature}; // SecretKey
+    use schnorrkel::{signing_context, verify_batch, batch_verify, Keypair, PublicKey, Signature}; // SecretKey
 
     // TODO: fn sign_mini(c: &mut Criterion)
 
@@ -60,6 +60,36 @@ mod schnorr_benches {
         }
     }
 
+    fn batch_verify_vs_sequential(c: &mut Criterion) {
+        const BATCH_SIZES: [usize; 2] = [100, 1000];
+
+        let mut group = c.benchmark_group("Schnorr batch_verify vs sequential verification");
+        for size in &BATCH_SIZES {
+            let keypairs: Vec<Keypair> = (0..*size).map(|_| Keypair::generate()).collect();
+            let ctx: &[u8] = b"this signature does this thing";
+            let msg: &[u8] = b"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+            let signatures: Vec<Signature> = keypairs
+                .iter()
+                .map(|key| key.sign_simple(ctx, msg))
+                .collect();
+            let public_keys: Vec<PublicKey> = keypairs.iter().map(|key| key.public).collect();
+            let contexts: Vec<&[u8]> = vec![ctx; *size];
+            let messages: Vec<&[u8]> = vec![msg; *size];
+
+            group.bench_with_input(BenchmarkId::new("batch", size), size, |b, _| {
+                b.iter(|| batch_verify(&public_keys[..], &contexts[..], &messages[..], &signatures[..]))
+            });
+            group.bench_with_input(BenchmarkId::new("sequential", size), size, |b, _| {
+                b.iter(|| {
+                    public_keys
+                        .iter()
+                        .zip(signatures.iter())
+                        .all(|(pk, sig)| pk.verify_simple(ctx, msg, sig).is_ok())
+                })
+            });
+        }
+    }
+
     fn key_generation(c: &mut Criterion) {
         c.bench_function("Schnorr keypair generation", move |b| {
             b.iter(|| Keypair::generate())
@@ -73,6 +103,7 @@ mod schnorr_benches {
             sign,
             verify,
             verify_batch_signatures,
+            batch_verify_vs_sequential,
             key_generation,
     }
 }
@@ -154,6 +154,95 @@ where
     verify_batch_equation( bs, zs, hrams, signatures, public_keys, deduplicate_public_keys )
 }
 
+/// Verify a batch of `signatures` on `messages`, each signed in the
+/// matching entry of `contexts`, with their respective `group_keys`.
+///
+/// A convenience form of `verify_batch` for signatures made on
+/// `signing_context(context).bytes(message)` transcripts, as produced
+/// by `sign_simple`.
+///
+/// Returns `false` rather than panicking if the slices have different
+/// lengths.
+pub fn batch_verify(
+    group_keys: &[PublicKey],
+    contexts: &[&[u8]],
+    messages: &[&[u8]],
+    signatures: &[Signature],
+) -> bool {
+    let n = signatures.len();
+    if group_keys.len() != n || contexts.len() != n || messages.len() != n {
+        return false;
+    }
+
+    let transcripts = contexts
+        .iter()
+        .zip(messages)
+        .map(|(ctx, msg)| signing_context(ctx).bytes(msg));
+    verify_batch(transcripts, signatures, group_keys, false).is_ok()
+}
+
+/// Verify that at least `k` distinct signers among `public_keys` made
+/// valid `signatures` on their respective `transcripts`.
+///
+/// Each public key counts towards the quorum at most once, so repeating
+/// one signer's valid signature never meets a larger quorum.
+///
+/// We first verify the whole set as one batch, which succeeds quickly
+/// when every signature is valid.  If the batch fails, we fall back to
+/// verifying signatures one by one, in order, and stop as soon as `k`
+/// distinct signers pass, so later signatures are never examined.
+///
+/// Returns `false` if the slices have different lengths or if `k`
+/// exceeds the number of signatures.
+pub fn verify_quorum<T, I>(
+    transcripts: I,
+    signatures: &[Signature],
+    public_keys: &[PublicKey],
+    k: usize,
+) -> bool
+where
+    T: SigningTranscript + Clone,
+    I: IntoIterator<Item = T>,
+{
+    let transcripts: Vec<T> = transcripts.into_iter().collect();
+    if transcripts.len() != signatures.len() || signatures.len() != public_keys.len() {
+        return false;
+    }
+    if k > signatures.len() {
+        return false;
+    }
+    if k == 0 {
+        return true;
+    }
+
+    let mut signers: Vec<[u8; 32]> =
+        public_keys.iter().map(|pk| pk.as_compressed().to_bytes()).collect();
+    signers.sort_unstable();
+    signers.dedup();
+    if signers.len() < k {
+        return false;
+    }
+
+    if verify_batch(transcripts.iter().cloned(), signatures, public_keys, false).is_ok() {
+        return true;
+    }
+
+    // Sorted list of signers whose signatures passed so far
+    signers.clear();
+    for ((t, sig), pk) in transcripts.into_iter().zip(signatures).zip(public_keys) {
+        let signer = pk.as_compressed().to_bytes();
+        if let Err(i) = signers.binary_search(&signer) {
+            if pk.verify(t, sig).is_ok() {
+                signers.insert(i, signer);
+                if signers.len() == k {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
 trait HasR {
     #[allow(non_snake_case)]
     fn get_R(&self) -> &CompressedRistretto;
@@ -459,4 +548,91 @@ mod test {
         let transcripts = messages.iter().map(|m| ctx.bytes(m));
         assert!(verify_batch(transcripts, &signatures[..], &public_keys[..], true).is_err());
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn verify_quorum_exactly_k() {
+        let ctx = signing_context(b"my quorum context");
+        let msg: &[u8] = b"Enough of us agree.";
+        let mut csprng: ThreadRng = thread_rng();
+
+        let keypairs: Vec<Keypair> = (0..7).map(|_| Keypair::generate_with(&mut csprng)).collect();
+        let public_keys: Vec<PublicKey> = keypairs.iter().map(|key| key.public).collect();
+        let mut signatures: Vec<Signature> =
+            keypairs.iter().map(|key| key.sign(ctx.bytes(msg))).collect();
+
+        // Four valid signatures come first and garbage after them, so the
+        // one by one fallback stops before reaching the invalid ones.
+        let bad = keypairs[0].sign(ctx.bytes(b"Something else entirely."));
+        for sig in &mut signatures[4..] {
+            *sig = bad;
+        }
+
+        let transcripts = || core::iter::repeat_n(ctx.bytes(msg), 7);
+        assert!(verify_quorum(transcripts(), &signatures[..], &public_keys[..], 4));
+        assert!(!verify_quorum(transcripts(), &signatures[..], &public_keys[..], 5));
+
+        // Three valid signatures no longer meet a quorum of four.
+        signatures[3] = bad;
+        assert!(!verify_quorum(transcripts(), &signatures[..], &public_keys[..], 4));
+        assert!(verify_quorum(transcripts(), &signatures[..], &public_keys[..], 3));
+
+        assert!(!verify_quorum(transcripts(), &signatures[..], &public_keys[..], 8));
+        assert!(!verify_quorum(transcripts(), &signatures[..6], &public_keys[..], 3));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn verify_quorum_counts_signers_once() {
+        let ctx = signing_context(b"my quorum context");
+        let msg: &[u8] = b"Enough of us agree.";
+        let mut csprng: ThreadRng = thread_rng();
+
+        let keypair: Keypair = Keypair::generate_with(&mut csprng);
+        let other: Keypair = Keypair::generate_with(&mut csprng);
+
+        // One signer's valid signature repeated three times
+        let mut signatures: Vec<Signature> = [keypair.sign(ctx.bytes(msg)); 3].to_vec();
+        let mut public_keys: Vec<PublicKey> = [keypair.public; 3].to_vec();
+
+        let transcripts = |n| core::iter::repeat_n(ctx.bytes(msg), n);
+        assert!(verify_quorum(transcripts(3), &signatures[..], &public_keys[..], 1));
+        assert!(!verify_quorum(transcripts(3), &signatures[..], &public_keys[..], 2));
+
+        signatures.push(other.sign(ctx.bytes(msg)));
+        public_keys.push(other.public);
+        assert!(verify_quorum(transcripts(4), &signatures[..], &public_keys[..], 2));
+        assert!(!verify_quorum(transcripts(4), &signatures[..], &public_keys[..], 3));
+
+        // The fallback path must not count the repeats either.
+        signatures[1] = other.sign(ctx.bytes(b"Something else entirely."));
+        assert!(verify_quorum(transcripts(4), &signatures[..], &public_keys[..], 2));
+        assert!(!verify_quorum(transcripts(4), &signatures[..], &public_keys[..], 3));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_verify_simple_contexts() {
+        let contexts: [&[u8]; 3] = [b"first context", b"second context", b"third context"];
+        let messages: [&[u8]; 3] = [b"one", b"two", b"three"];
+        let mut csprng: ThreadRng = thread_rng();
+
+        let keypairs: Vec<Keypair> = (0..3).map(|_| Keypair::generate_with(&mut csprng)).collect();
+        let public_keys: Vec<PublicKey> = keypairs.iter().map(|key| key.public).collect();
+        let mut signatures: Vec<Signature> = keypairs
+            .iter()
+            .zip(contexts.iter().zip(messages.iter()))
+            .map(|(key, (ctx, msg))| key.sign_simple(ctx, msg))
+            .collect();
+
+        assert!(batch_verify(&public_keys[..], &contexts[..], &messages[..], &signatures[..]));
+
+        assert!(!batch_verify(&public_keys[..2], &contexts[..], &messages[..], &signatures[..]));
+        assert!(!batch_verify(&public_keys[..], &contexts[..2], &messages[..], &signatures[..]));
+        assert!(!batch_verify(&public_keys[..], &contexts[..], &messages[..2], &signatures[..]));
+        assert!(!batch_verify(&public_keys[..], &contexts[..], &messages[..], &signatures[..2]));
+
+        signatures[1] = keypairs[1].sign_simple(contexts[1], b"something else");
+        assert!(!batch_verify(&public_keys[..], &contexts[..], &messages[..], &signatures[..]));
+    }
 }
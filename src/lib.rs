@@ -265,11 +265,14 @@ pub mod musig;
 
 pub use crate::keys::*; // {MiniSecretKey,SecretKey,PublicKey,Keypair,ExpansionMode}; + *_LENGTH
 pub use crate::context::{signing_context}; // SigningContext,SigningTranscript
-pub use crate::sign::{Signature, SIGNATURE_LENGTH};
+pub use crate::sign::{Signature, SIGNATURE_LENGTH, DisclosureProof, DISCLOSURE_PROOF_LENGTH};
 pub use crate::errors::{SignatureError, SignatureResult};
 
 #[cfg(feature = "alloc")]
-pub use crate::batch::{verify_batch, verify_batch_rng, verify_batch_deterministic, PreparedBatch};
+pub use crate::batch::{
+    verify_batch, verify_batch_rng, verify_batch_deterministic, verify_quorum, batch_verify,
+    PreparedBatch,
+};
 
 pub(crate) fn scalar_from_canonical_bytes(bytes: [u8; 32]) -> Option<Scalar> {
     let key = Scalar::from_canonical_bytes(bytes);
@@ -17,6 +17,8 @@ use curve25519_dalek::constants;
 use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
 use curve25519_dalek::scalar::Scalar;
 
+use subtle::{Choice, ConstantTimeEq};
+
 use super::*;
 use crate::context::{SigningTranscript, SigningContext};
 
@@ -30,7 +32,7 @@ pub const SIGNATURE_LENGTH: usize = 64;
 /// These cannot be converted to any Ed25519 signature because they hash
 /// curve points in the Ristretto encoding.
 #[allow(non_snake_case)]
-#[derive(Clone, Copy, Eq, PartialEq)]
+#[derive(Clone, Copy)]
 pub struct Signature {
     /// `R` is a `RistrettoPoint`, formed by using an hash function with
     /// 512-bits output to produce the digest of:
@@ -61,6 +63,18 @@ impl Debug for Signature {
     }
 }
 
+impl Eq for Signature {}
+impl PartialEq for Signature {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).unwrap_u8() == 1u8
+    }
+}
+impl ConstantTimeEq for Signature {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.R.ct_eq(&other.R) & self.s.ct_eq(&other.s)
+    }
+}
+
 pub(crate) fn check_scalar(bytes: [u8; 32]) -> SignatureResult<Scalar> {
     // Since this is only used in signature deserialisation (i.e. upon
     // verification), we can do a "succeed fast" trick by checking that the most
@@ -219,6 +233,17 @@ impl SecretKey {
 }
 
 impl PublicKey {
+    /// Commit this public key and the signature's `R` to the transcript
+    /// and return the Schnorr challenge `k`.
+    #[allow(non_snake_case)]
+    fn signing_challenge<T: SigningTranscript>(&self, t: &mut T, R: &CompressedRistretto) -> Scalar {
+        t.proto_name(b"Schnorr-sig");
+        t.commit_point(b"sign:pk", self.as_compressed());
+        t.commit_point(b"sign:R", R);
+
+        t.challenge_scalar(b"sign:c") // context, message, A/public_key, R=rG
+    }
+
     /// Verify a signature by this public key on a transcript.
     ///
     /// Requires a `SigningTranscript`, normally created from a
@@ -232,11 +257,7 @@ impl PublicKey {
     ) -> SignatureResult<()> {
         let A: &RistrettoPoint = self.as_point();
 
-        t.proto_name(b"Schnorr-sig");
-        t.commit_point(b"sign:pk", self.as_compressed());
-        t.commit_point(b"sign:R", &signature.R);
-
-        let k: Scalar = t.challenge_scalar(b"sign:c"); // context, message, A/public_key, R=rG
+        let k: Scalar = self.signing_challenge(&mut t, &signature.R);
         let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(&k, &(-A), &signature.s);
 
         if R.compress() == signature.R {
@@ -470,6 +491,175 @@ impl Keypair {
     }
 }
 
+// === Designated verifier disclosure proofs === //
+
+/// The length of a `DisclosureProof`, in bytes.
+pub const DISCLOSURE_PROOF_LENGTH: usize = 160;
+
+/// Designated verifier proof that we hold a valid `Signature` on some
+/// message, which does not hand over the reusable signature itself.
+///
+/// We reveal the signature's `R` and prove, for one verifier's public
+/// key `V`, either knowledge of an `s` with `s G = R + k A` or knowledge
+/// of the verifier's secret key.  As only we know the former, the
+/// designated verifier becomes convinced.  Yet the verifier could
+/// produce such a proof themselves using their secret key, so the proof
+/// convinces nobody else and cannot be transferred.
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisclosureProof {
+    /// `R` of the disclosed signature
+    R: CompressedRistretto,
+    /// Challenge share for the signature branch
+    c_sig: Scalar,
+    /// Schnorr proof for the signature branch
+    s_sig: Scalar,
+    /// Challenge share for the verifier key branch
+    c_ver: Scalar,
+    /// Schnorr proof for the verifier key branch
+    s_ver: Scalar,
+}
+
+impl DisclosureProof {
+    const DESCRIPTION: &'static str = "A Ristretto Schnorr designated verifier disclosure proof, which consists of a Ristretto compressed point and four scalars, making it 160 bytes.";
+
+    /// Convert this `DisclosureProof` to a byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; DISCLOSURE_PROOF_LENGTH] {
+        let mut bytes = [0u8; DISCLOSURE_PROOF_LENGTH];
+
+        bytes[0..32].copy_from_slice(&self.R.as_bytes()[..]);
+        bytes[32..64].copy_from_slice(&self.c_sig.as_bytes()[..]);
+        bytes[64..96].copy_from_slice(&self.s_sig.as_bytes()[..]);
+        bytes[96..128].copy_from_slice(&self.c_ver.as_bytes()[..]);
+        bytes[128..160].copy_from_slice(&self.s_ver.as_bytes()[..]);
+        bytes
+    }
+
+    /// Construct a `DisclosureProof` from a slice of bytes.
+    #[allow(non_snake_case)]
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> SignatureResult<DisclosureProof> {
+        if bytes.len() != DISCLOSURE_PROOF_LENGTH {
+            return Err(SignatureError::BytesLengthError {
+                name: "DisclosureProof",
+                description: DisclosureProof::DESCRIPTION,
+                length: DISCLOSURE_PROOF_LENGTH,
+            });
+        }
+        let scalar = |i: usize| {
+            let mut s: [u8; 32] = [0u8; 32];
+            s.copy_from_slice(&bytes[i..i + 32]);
+            crate::scalar_from_canonical_bytes(s).ok_or(SignatureError::ScalarFormatError)
+        };
+
+        let mut R: [u8; 32] = [0u8; 32];
+        R.copy_from_slice(&bytes[0..32]);
+
+        Ok(DisclosureProof {
+            R: CompressedRistretto(R),
+            c_sig: scalar(32)?,
+            s_sig: scalar(64)?,
+            c_ver: scalar(96)?,
+            s_ver: scalar(128)?,
+        })
+    }
+}
+
+serde_boilerplate!(DisclosureProof);
+
+/// Commit the public parts of a `DisclosureProof` and return its challenge,
+/// which the two branch challenges must sum to.
+#[allow(non_snake_case)]
+fn disclosure_challenge<T: SigningTranscript>(
+    mut t: T,
+    public_key: &PublicKey,
+    verifier: &PublicKey,
+    R: &CompressedRistretto,
+    T_sig: &RistrettoPoint,
+    T_ver: &RistrettoPoint,
+) -> Scalar {
+    t.proto_name(b"Schnorr-disclosure");
+    t.commit_point(b"disclose:pk", public_key.as_compressed());
+    t.commit_point(b"disclose:verifier", verifier.as_compressed());
+    t.commit_point(b"disclose:R", R);
+    t.commit_point(b"disclose:T_sig", &T_sig.compress());
+    t.commit_point(b"disclose:T_ver", &T_ver.compress());
+
+    t.challenge_scalar(b"disclose:c")
+}
+
+impl Signature {
+    /// Prove to the holder of `verifier` that this is a valid signature
+    /// by `public_key` on the transcript `t`, without revealing `s`.
+    ///
+    /// Returns an error if the signature does not verify.
+    #[allow(non_snake_case)]
+    pub fn to_disclosure_proof<T>(
+        &self,
+        t: T,
+        public_key: &PublicKey,
+        verifier: &PublicKey,
+    ) -> SignatureResult<DisclosureProof>
+    where
+        T: SigningTranscript + Clone,
+    {
+        public_key.verify(t.clone(), self)?;
+
+        // Simulate the verifier key branch, whose secret we lack.
+        let c_ver = t.witness_scalar(b"disclose:c_ver", &[&self.s.as_bytes()[..]]);
+        let s_ver = t.witness_scalar(b"disclose:s_ver", &[&self.s.as_bytes()[..]]);
+        let T_ver =
+            RistrettoPoint::vartime_double_scalar_mul_basepoint(&c_ver, verifier.as_point(), &s_ver);
+
+        // Prove the signature branch, where s G = R + k A.
+        let mut r = t.witness_scalar(b"disclose:r", &[&self.s.as_bytes()[..]]);
+        let T_sig = &r * constants::RISTRETTO_BASEPOINT_TABLE;
+
+        let c = disclosure_challenge(t, public_key, verifier, &self.R, &T_sig, &T_ver);
+        let c_sig = c - c_ver;
+        let s_sig = r - c_sig * self.s;
+
+        zeroize::Zeroize::zeroize(&mut r);
+
+        Ok(DisclosureProof { R: self.R, c_sig, s_sig, c_ver, s_ver })
+    }
+}
+
+impl PublicKey {
+    /// Verify a `DisclosureProof` that this public key signed the
+    /// transcript `t`, made for the holder of `verifier`.
+    #[allow(non_snake_case)]
+    pub fn verify_disclosure<T>(
+        &self,
+        t: T,
+        proof: &DisclosureProof,
+        verifier: &PublicKey,
+    ) -> SignatureResult<()>
+    where
+        T: SigningTranscript + Clone,
+    {
+        let k: Scalar = self.signing_challenge(&mut t.clone(), &proof.R);
+        let R = proof.R.decompress().ok_or(SignatureError::PointDecompressionError)?;
+        let sG = R + k * self.as_point();
+
+        let T_sig =
+            RistrettoPoint::vartime_double_scalar_mul_basepoint(&proof.c_sig, &sG, &proof.s_sig);
+        let T_ver = RistrettoPoint::vartime_double_scalar_mul_basepoint(
+            &proof.c_ver,
+            verifier.as_point(),
+            &proof.s_ver,
+        );
+
+        let c = disclosure_challenge(t, self, verifier, &proof.R, &T_sig, &T_ver);
+        if c == proof.c_sig + proof.c_ver {
+            Ok(())
+        } else {
+            Err(SignatureError::EquationFalse)
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use sha3::Shake128;
@@ -559,6 +749,105 @@ mod test {
         );
     }
 
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn signature_ct_eq() {
+        use subtle::ConstantTimeEq;
+
+        let ctx = signing_context(b"good");
+        let keypair = Keypair::generate_with(&mut rand_core::OsRng);
+
+        let sig = keypair.sign(ctx.bytes(b"test message"));
+        let same = Signature::from_bytes(&sig.to_bytes()[..]).unwrap();
+        let other = keypair.sign(ctx.bytes(b"wrong message"));
+
+        assert_eq!(sig.ct_eq(&same).unwrap_u8(), 1u8);
+        assert_eq!(sig, same);
+        assert_eq!(sig.ct_eq(&other).unwrap_u8(), 0u8);
+        assert_ne!(sig, other);
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn disclosure_proof() {
+        let ctx = signing_context(b"disclosure");
+        let keypair = Keypair::generate_with(&mut rand_core::OsRng);
+        let verifier = Keypair::generate_with(&mut rand_core::OsRng);
+        let other = Keypair::generate_with(&mut rand_core::OsRng);
+
+        let sig = keypair.sign(ctx.bytes(b"test message"));
+        let proof = sig
+            .to_disclosure_proof(ctx.bytes(b"test message"), &keypair.public, &verifier.public)
+            .unwrap();
+        let proof = DisclosureProof::from_bytes(&proof.to_bytes()[..]).unwrap();
+
+        assert!(keypair
+            .public
+            .verify_disclosure(ctx.bytes(b"test message"), &proof, &verifier.public)
+            .is_ok());
+        assert!(keypair
+            .public
+            .verify_disclosure(ctx.bytes(b"test message"), &proof, &other.public)
+            .is_err());
+        assert!(keypair
+            .public
+            .verify_disclosure(ctx.bytes(b"wrong message"), &proof, &verifier.public)
+            .is_err());
+        assert!(other
+            .public
+            .verify_disclosure(ctx.bytes(b"test message"), &proof, &verifier.public)
+            .is_err());
+        assert!(sig
+            .to_disclosure_proof(ctx.bytes(b"wrong message"), &keypair.public, &verifier.public)
+            .is_err());
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn disclosure_proof_forgeable_by_verifier() {
+        use curve25519_dalek::constants;
+        use curve25519_dalek::ristretto::RistrettoPoint;
+        use curve25519_dalek::scalar::Scalar;
+        use rand::RngCore;
+
+        let random_scalar = || {
+            let mut bytes = [0u8; 64];
+            rand_core::OsRng.fill_bytes(&mut bytes);
+            Scalar::from_bytes_mod_order_wide(&bytes)
+        };
+
+        let ctx = signing_context(b"disclosure");
+        let keypair = Keypair::generate_with(&mut rand_core::OsRng);
+        let verifier = Keypair::generate_with(&mut rand_core::OsRng);
+
+        // The verifier fakes a proof for a message that was never signed,
+        // using their own secret key, so their proofs convince nobody else.
+        let t = ctx.bytes(b"never signed");
+        let R = (&random_scalar() * constants::RISTRETTO_BASEPOINT_TABLE).compress();
+        let k = keypair.public.signing_challenge(&mut t.clone(), &R);
+        let sG = R.decompress().unwrap() + k * keypair.public.as_point();
+
+        let (c_sig, s_sig) = (random_scalar(), random_scalar());
+        let T_sig = RistrettoPoint::vartime_double_scalar_mul_basepoint(&c_sig, &sG, &s_sig);
+        let r = random_scalar();
+        let T_ver = &r * constants::RISTRETTO_BASEPOINT_TABLE;
+
+        let c = sign::disclosure_challenge(
+            t.clone(),
+            &keypair.public,
+            &verifier.public,
+            &R,
+            &T_sig,
+            &T_ver,
+        );
+        let c_ver = c - c_sig;
+        let s_ver = r - c_ver * verifier.secret.key;
+        let forged = DisclosureProof { R, c_sig, s_sig, c_ver, s_ver };
+
+        assert!(keypair.public.verify_disclosure(t, &forged, &verifier.public).is_ok());
+    }
+
     #[cfg(feature = "preaudit_deprecated")]
     #[test]
     fn can_verify_know_preaudit_deprecated_message() {